@@ -11,12 +11,16 @@ impl Plugin for ThermodynamicsPlugin {
         app.register_type::<thermal::Temperature>()
             .register_type::<thermal::ThermalConductivity>()
             .register_type::<thermal::ThermalDiffusivity>()
+            .register_type::<thermal::Convection>()
             .register_type::<entropy::Entropy>()
             .register_type::<entropy::Reversibility>()
             .register_type::<equilibrium::ThermalEquilibrium>()
             .register_type::<equilibrium::PhaseState>()
             .add_event::<thermal::ThermalTransferEvent>()
-            .add_systems(Update, thermal::calculate_thermal_transfer);
+            .add_systems(
+                Update,
+                (thermal::calculate_thermal_transfer, thermal::apply_convection),
+            );
     }
 }
 
@@ -30,6 +34,7 @@ pub mod prelude {
         ThermalProperties,
     };
     pub use super::thermal::{
-        thermal_utils::heat_conduction, Temperature, ThermalConductivity, ThermalDiffusivity,
+        polytropic_temperature, thermal_utils::heat_conduction, Convection, PolytropicProcess,
+        Temperature, ThermalConductivity, ThermalDiffusivity,
     };
 }
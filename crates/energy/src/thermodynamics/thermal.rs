@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+
+/// Component storing an entity's temperature in Kelvin.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Temperature {
+    pub kelvin: f32,
+}
+
+impl Temperature {
+    pub fn from_celsius(celsius: f32) -> Self {
+        Self {
+            kelvin: celsius + 273.15,
+        }
+    }
+
+    pub fn to_celsius(&self) -> f32 {
+        self.kelvin - 273.15
+    }
+}
+
+/// Component for thermal conductivity (W/(m·K)), governing conductive heat transfer.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct ThermalConductivity {
+    pub value: f32,
+}
+
+/// Component for thermal diffusivity (m²/s), governing how quickly heat spreads through
+/// a material.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct ThermalDiffusivity {
+    pub value: f32,
+}
+
+/// Event emitted whenever heat is transferred into or out of an entity.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ThermalTransferEvent {
+    pub entity: Entity,
+    /// Heat-transfer rate in Watts, as computed by the emitting system's formula. For
+    /// [`apply_convection`] this is `h * A * (T - T_env)`: positive when the entity is
+    /// hotter than ambient and losing heat.
+    pub heat: f32,
+}
+
+pub mod thermal_utils {
+    /// Heat conduction via Fourier's law: `Q = -k * A * (dT/dx)`.
+    pub fn heat_conduction(conductivity: f32, area: f32, temperature_gradient: f32) -> f32 {
+        -conductivity * area * temperature_gradient
+    }
+}
+
+/// System that computes conductive heat transfer for entities carrying
+/// [`ThermalConductivity`] and [`Temperature`] via [`thermal_utils::heat_conduction`]
+/// over a unit area, with the entity's temperature passed as the gradient, and emits a
+/// [`ThermalTransferEvent`] per entity with positive conductivity. It does not change
+/// [`Temperature`].
+pub fn calculate_thermal_transfer(
+    mut events: EventWriter<ThermalTransferEvent>,
+    query: Query<(Entity, &Temperature, &ThermalConductivity)>,
+) {
+    for (entity, temperature, conductivity) in query.iter() {
+        if conductivity.value <= 0.0 {
+            continue;
+        }
+
+        let heat = thermal_utils::heat_conduction(conductivity.value, 1.0, temperature.kelvin);
+        events.send(ThermalTransferEvent { entity, heat });
+    }
+}
+
+/// Heat-transfer coefficient, surface area, mass, specific heat capacity, and ambient
+/// temperature driving convective cooling/heating via Newton's law of cooling.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Convection {
+    /// Heat-transfer coefficient `h`, in W/(m²·K).
+    pub coefficient: f32,
+    /// Surface area `A` exposed to the surrounding fluid/gas, in m².
+    pub area: f32,
+    /// Mass `m`, in kg.
+    pub mass: f32,
+    /// Specific heat capacity `c`, in J/(kg·K).
+    pub specific_heat: f32,
+    /// Ambient temperature `T_env` the entity relaxes toward.
+    pub ambient: Temperature,
+}
+
+/// System applying Newton's law of cooling, `Q = h * A * (T - T_env)`, and relaxing
+/// [`Temperature`] toward [`Convection::ambient`] with the closed-form exponential
+/// solution `T(t) = T_env + (T0 - T_env) * exp(-(hA/mc) * t)`, which stays stable at
+/// large `dt` unlike a forward-Euler update of the same ODE.
+pub fn apply_convection(
+    time: Res<Time>,
+    mut events: EventWriter<ThermalTransferEvent>,
+    mut query: Query<(Entity, &mut Temperature, &Convection)>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut temperature, convection) in query.iter_mut() {
+        let heat_capacity = convection.mass * convection.specific_heat;
+        if heat_capacity <= 0.0 {
+            continue;
+        }
+
+        let delta = temperature.kelvin - convection.ambient.kelvin;
+        let heat = convection.coefficient * convection.area * delta;
+
+        let relaxation_rate = (convection.coefficient * convection.area) / heat_capacity;
+        let new_delta = delta * (-relaxation_rate * dt).exp();
+        temperature.kelvin = convection.ambient.kelvin + new_delta;
+
+        events.send(ThermalTransferEvent { entity, heat });
+    }
+}
+
+/// Polytropic index for common gas processes (`P * V^n = const`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PolytropicProcess {
+    /// `n = 1`: constant temperature.
+    Isothermal,
+    /// `n = gamma`: no heat exchanged with the surroundings.
+    Adiabatic { gamma: f32 },
+    /// `n = 0`: constant pressure.
+    Isobaric,
+    /// Any other polytropic index.
+    Custom { n: f32 },
+}
+
+impl PolytropicProcess {
+    pub fn index(&self) -> f32 {
+        match self {
+            PolytropicProcess::Isothermal => 1.0,
+            PolytropicProcess::Adiabatic { gamma } => *gamma,
+            PolytropicProcess::Isobaric => 0.0,
+            PolytropicProcess::Custom { n } => *n,
+        }
+    }
+}
+
+/// Computes the post-compression/expansion temperature for a polytropic process
+/// `P * V^n = const`, via `T2 = T1 * (V1 / V2)^(n - 1)`.
+pub fn polytropic_temperature(
+    initial: Temperature,
+    initial_volume: f32,
+    final_volume: f32,
+    process: PolytropicProcess,
+) -> Temperature {
+    let n = process.index();
+    let ratio = (initial_volume / final_volume).powf(n - 1.0);
+
+    Temperature {
+        kelvin: initial.kelvin * ratio,
+    }
+}
@@ -0,0 +1,141 @@
+/// Grid-based finite-difference wave solver
+///
+/// Complements [`crate::wave_equation`]'s closed-form traveling-wave solution with a
+/// numerical field that can model reflection, interference, and arbitrary initial
+/// conditions — things the analytic solution alone can't represent.
+use bevy::prelude::*;
+
+use crate::wave_equation::{solve_wave_1d, WaveParameters};
+
+/// Boundary condition applied at both ends of a [`WaveField`] grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryCondition {
+    /// Fixed edge, `u = 0` (reflects with phase inversion).
+    Fixed,
+    /// Free edge, zero gradient (reflects without inversion).
+    Free,
+    /// One-way edge update that lets outgoing waves leave without reflecting when the
+    /// Courant number is exactly `1`; at smaller Courant numbers part of the wave reflects.
+    Absorbing,
+}
+
+/// A 1D finite-difference wave field, advanced with the explicit leapfrog scheme:
+///
+/// `u_next[i] = 2*u[i] - u_prev[i] + C² * (u[i+1] - 2*u[i] + u[i-1])`
+///
+/// where the Courant number `C = speed * dt / dx` must stay `<= 1` for stability.
+#[derive(Component, Debug, Clone)]
+pub struct WaveField {
+    /// Amplitude at the current step.
+    pub current: Vec<f32>,
+    /// Amplitude at the previous step.
+    pub previous: Vec<f32>,
+    /// Scratch buffer for the next step, rotated in after each update.
+    pub next: Vec<f32>,
+    /// Grid spacing between samples.
+    pub dx: f32,
+    /// Boundary condition applied at both ends of the grid.
+    pub boundary: BoundaryCondition,
+}
+
+impl WaveField {
+    pub fn new(size: usize, dx: f32, boundary: BoundaryCondition) -> Self {
+        Self {
+            current: vec![0.0; size],
+            previous: vec![0.0; size],
+            next: vec![0.0; size],
+            dx,
+            boundary,
+        }
+    }
+
+    /// Courant number `C = speed * dt / dx`; must stay `<= 1` for the leapfrog update to
+    /// be stable.
+    #[inline]
+    pub fn courant_number(&self, speed: f32, dt: f32) -> f32 {
+        speed * dt / self.dx
+    }
+
+    /// The largest stable `dt` for this grid at the given wave `speed`.
+    #[inline]
+    pub fn max_stable_dt(&self, speed: f32) -> f32 {
+        self.dx / speed
+    }
+
+    /// Injects a displacement at `index`, e.g. for a point or line source.
+    pub fn inject(&mut self, index: usize, amplitude: f32) {
+        if let Some(value) = self.current.get_mut(index) {
+            *value += amplitude;
+        }
+    }
+
+    fn apply_boundary(&mut self) {
+        let last = match self.next.len().checked_sub(1) {
+            Some(last) if last > 0 => last,
+            _ => return,
+        };
+
+        match self.boundary {
+            BoundaryCondition::Fixed => {
+                self.next[0] = 0.0;
+                self.next[last] = 0.0;
+            }
+            BoundaryCondition::Free => {
+                self.next[0] = self.next[1];
+                self.next[last] = self.next[last - 1];
+            }
+            BoundaryCondition::Absorbing => {
+                // One-way update: the edge just takes on the amplitude already
+                // propagating past its inner neighbor. This matches the wave's travel
+                // exactly only at Courant number 1 (one cell per step); below that,
+                // outgoing waves partly reflect back into the grid.
+                self.next[0] = self.current[1];
+                self.next[last] = self.current[last - 1];
+            }
+        }
+    }
+
+    /// Advances the field by one leapfrog step. Clamp or warn on `dt` before calling this
+    /// if [`WaveField::courant_number`] would exceed `1`.
+    pub fn step(&mut self, speed: f32, dt: f32) {
+        let courant_squared = self.courant_number(speed, dt).powi(2);
+
+        for i in 1..self.current.len().saturating_sub(1) {
+            let laplacian = self.current[i + 1] - 2.0 * self.current[i] + self.current[i - 1];
+            self.next[i] = 2.0 * self.current[i] - self.previous[i] + courant_squared * laplacian;
+        }
+
+        self.apply_boundary();
+
+        std::mem::swap(&mut self.previous, &mut self.current);
+        std::mem::swap(&mut self.current, &mut self.next);
+    }
+}
+
+/// Component marking a [`WaveField`] entity as a point source, injecting a
+/// [`WaveParameters`]-driven displacement at `grid_index` every step.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WaveSource {
+    pub grid_index: usize,
+}
+
+/// System that advances every [`WaveField`] by one step, clamping `dt` so the Courant
+/// number stays within the stability limit, and injecting each field's [`WaveSource`]
+/// (if any) using the same [`solve_wave_1d`] solution the analytic module exposes.
+pub fn update_wave_fields(
+    time: Res<Time>,
+    mut query: Query<(&mut WaveField, &WaveParameters, Option<&WaveSource>)>,
+) {
+    let t = time.elapsed_secs();
+
+    for (mut field, params, source) in query.iter_mut() {
+        let dt = time.delta_secs().min(field.max_stable_dt(params.speed));
+
+        if let Some(source) = source {
+            let displacement = solve_wave_1d(params, 0.0, t);
+            field.inject(source.grid_index, displacement);
+        }
+
+        field.step(params.speed, dt);
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+
+use crate::mechanics::{calculate_kinetic_energy, calculate_momentum, Mass, Velocity};
+
+/// Resource gating system-wide momentum conservation. When `enabled`, [`remove_system_drift`]
+/// removes the center-of-mass velocity from every finite-mass body each step so
+/// accumulated numerical error can't translate the whole system over time.
+///
+/// Disabled by default: this only makes sense for closed systems meant to have zero (or
+/// a fixed) total momentum, e.g. molecular-dynamics-style N-body setups. For the common
+/// case of a few dynamic bodies next to an infinite-mass floor or wall, the
+/// center-of-mass velocity computed here is just those bodies' own velocity, so enabling
+/// it unconditionally would zero their motion every physics step.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ConserveMomentum {
+    pub enabled: bool,
+    /// Total system momentum to conserve toward (zero by default).
+    pub target: Vec3,
+}
+
+impl Default for ConserveMomentum {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target: Vec3::ZERO,
+        }
+    }
+}
+
+/// Subtracts the center-of-mass velocity of every finite-mass body in `query` so the
+/// system's total momentum equals `target`.
+fn remove_drift(target: Vec3, query: &mut Query<(&Mass, &mut Velocity)>) {
+    let mut total_momentum = Vec3::ZERO;
+    let mut total_mass = 0.0;
+
+    for (mass, velocity) in query.iter() {
+        if mass.is_infinite || mass.is_negligible() {
+            continue;
+        }
+
+        total_momentum += calculate_momentum(mass, &velocity);
+        total_mass += mass.value;
+    }
+
+    if total_mass < f32::EPSILON {
+        return;
+    }
+
+    let center_of_mass_velocity = (total_momentum - target) / total_mass;
+
+    for (mass, mut velocity) in query.iter_mut() {
+        if mass.is_infinite || mass.is_negligible() {
+            continue;
+        }
+
+        velocity.linvel -= center_of_mass_velocity;
+    }
+}
+
+/// System that removes spurious net momentum accumulated from numerical error by
+/// subtracting the system's center-of-mass velocity from every finite-mass body,
+/// keeping many-body/N-body simulations from slowly translating away from their origin.
+pub fn remove_system_drift(
+    conserve: Res<ConserveMomentum>,
+    mut query: Query<(&Mass, &mut Velocity)>,
+) {
+    if !conserve.enabled {
+        return;
+    }
+
+    remove_drift(conserve.target, &mut query);
+}
+
+/// Small xorshift-based PRNG so seeding doesn't need an external `rand` dependency.
+/// Returns a unit-length vector pointing in a pseudo-random direction.
+fn pseudo_random_unit_axis(seed: u32) -> Vec3 {
+    let mut state = seed.wrapping_mul(2_654_435_761).wrapping_add(1);
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        (state as f32 / u32::MAX as f32) * 2.0 - 1.0
+    };
+
+    let axis = Vec3::new(next(), next(), next());
+    if axis.length_squared() < f32::EPSILON {
+        Vec3::X
+    } else {
+        axis.normalize()
+    }
+}
+
+/// Seeds every finite-mass body in `query` with a random velocity, removes drift so the
+/// center of mass stays stationary, then scales the velocities so the system's total
+/// kinetic energy matches `target_kinetic_energy`. Useful for initializing stable
+/// many-body or molecular-dynamics-style simulations.
+pub fn velocitize(target_kinetic_energy: f32, query: &mut Query<(&Mass, &mut Velocity)>) {
+    let mut seed = 0u32;
+    for (mass, mut velocity) in query.iter_mut() {
+        if mass.is_infinite || mass.is_negligible() {
+            continue;
+        }
+
+        velocity.linvel = pseudo_random_unit_axis(seed);
+        seed = seed.wrapping_add(1);
+    }
+
+    // Remove drift before scaling: scaling keeps zero momentum at zero, whereas removing
+    // drift afterwards would lower the kinetic energy below the target.
+    remove_drift(Vec3::ZERO, query);
+
+    let mut total_kinetic_energy = 0.0;
+    for (mass, velocity) in query.iter() {
+        if mass.is_infinite || mass.is_negligible() {
+            continue;
+        }
+
+        total_kinetic_energy += calculate_kinetic_energy(mass, &velocity);
+    }
+
+    if total_kinetic_energy > f32::EPSILON {
+        let scale = (target_kinetic_energy / total_kinetic_energy).sqrt();
+        for (mass, mut velocity) in query.iter_mut() {
+            if mass.is_infinite || mass.is_negligible() {
+                continue;
+            }
+
+            velocity.linvel *= scale;
+        }
+    }
+}
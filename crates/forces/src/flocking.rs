@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+use crate::mechanics::{AppliedForce, Distance, Mass, Norm, Velocity};
+use crate::schedule::PhysicsSettings;
+
+/// Component configuring a boid's flocking behavior, steered by [`flock`] into its
+/// [`AppliedForce`] so ordinary force integration moves it.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Boid {
+    /// Radius within which other boids are considered neighbors.
+    pub perception_radius: f32,
+    /// Maximum speed a boid's steering is allowed to drive it to.
+    pub max_speed: f32,
+    /// Weight of the separation steering term (avoid crowding neighbors).
+    pub separation_weight: f32,
+    /// Weight of the alignment steering term (match neighbors' heading).
+    pub alignment_weight: f32,
+    /// Weight of the cohesion steering term (move toward neighbors' center).
+    pub cohesion_weight: f32,
+}
+
+impl Default for Boid {
+    fn default() -> Self {
+        Self {
+            perception_radius: 5.0,
+            max_speed: 4.0,
+            separation_weight: 1.5,
+            alignment_weight: 1.0,
+            cohesion_weight: 1.0,
+        }
+    }
+}
+
+/// System that steers each [`Boid`] based on neighbors within `perception_radius`,
+/// blending separation, alignment, and cohesion by their weights and writing the result
+/// into the entity's [`AppliedForce`].
+pub fn flock(
+    settings: Res<PhysicsSettings>,
+    mut boids: Query<(Entity, &Boid, &Transform, &Velocity, &Mass, &mut AppliedForce)>,
+    neighbors: Query<(Entity, &Transform, &Velocity), (With<Boid>, With<Mass>)>,
+) {
+    let dt = settings.delta_time;
+
+    for (entity, boid, transform, velocity, mass, mut force) in boids.iter_mut() {
+        let perception_radius_squared = boid.perception_radius * boid.perception_radius;
+
+        let mut separation = Vec3::ZERO;
+        let mut average_velocity = Vec3::ZERO;
+        let mut average_position = Vec3::ZERO;
+        let mut neighbor_count = 0u32;
+
+        for (other_entity, other_transform, other_velocity) in neighbors.iter() {
+            if other_entity == entity {
+                continue;
+            }
+
+            let offset = transform.translation - other_transform.translation;
+            let distance_squared =
+                Distance::distance_squared(transform.translation, other_transform.translation);
+
+            if distance_squared > perception_radius_squared || distance_squared < f32::EPSILON {
+                continue;
+            }
+
+            // Weight by inverse distance so closer neighbors push harder.
+            separation += offset.normalize() / distance_squared.sqrt();
+            average_velocity += other_velocity.linvel;
+            average_position += other_transform.translation;
+            neighbor_count += 1;
+        }
+
+        if neighbor_count == 0 {
+            force.force = Vec3::ZERO;
+            continue;
+        }
+
+        let neighbor_count = neighbor_count as f32;
+        average_velocity /= neighbor_count;
+        average_position /= neighbor_count;
+
+        let alignment = average_velocity - velocity.linvel;
+        let cohesion = average_position - transform.translation;
+
+        let mut steering = separation * boid.separation_weight
+            + alignment * boid.alignment_weight
+            + cohesion * boid.cohesion_weight;
+
+        // Clamp the resulting velocity (not just the steering force) to max_speed.
+        // `apply_forces` turns this force into a velocity change of
+        // `force.force * mass.inverse() * dt`, not a 1:1 addition, so the predicted
+        // next velocity (and the steering needed to hit a clamped target) must account
+        // for that gain instead of assuming it's 1.
+        let gain = mass.inverse() * dt;
+        if gain > f32::EPSILON {
+            let predicted_velocity = velocity.linvel + steering * gain;
+            if predicted_velocity.norm_squared() > boid.max_speed * boid.max_speed {
+                let desired_velocity = predicted_velocity.normalize() * boid.max_speed;
+                steering = (desired_velocity - velocity.linvel) / gain;
+            }
+        }
+
+        // Overwrite rather than accumulate: steering is fully recomputed from the
+        // current neighborhood each step, and nothing else clears AppliedForce between
+        // steps, so `+=` would compound every past step's steering indefinitely.
+        force.force = steering;
+    }
+}
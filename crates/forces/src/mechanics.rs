@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::schedule::PhysicsSettings;
+
 /// Trait for computing the squared norm of a vector efficiently
 pub trait Norm {
     type Output;
@@ -129,49 +131,62 @@ impl AppliedForce {
     }
 }
 
+/// Arbitrary limit on acceleration magnitude to prevent numerical issues.
+const MAX_ACCELERATION: f32 = 1000.0;
+
+/// Caps acceleration magnitude at [`MAX_ACCELERATION`] to prevent instability.
+#[inline]
+fn capped_acceleration(acceleration: Vec3) -> Vec3 {
+    if acceleration.norm_squared() > MAX_ACCELERATION * MAX_ACCELERATION {
+        acceleration.normalize() * MAX_ACCELERATION
+    } else {
+        acceleration
+    }
+}
+
 /// System to apply forces according to Newton's Second Law (F = ma)
+///
+/// Runs in [`crate::schedule::PhysicsSet::Main`] ahead of [`integrate_positions`], so the
+/// velocity it produces is the one integration uses this step (semi-implicit/symplectic
+/// Euler) rather than the velocity from the previous step (explicit Euler). Entities with
+/// [`PreviousAcceleration`] opt into [`integrate_positions_verlet`] instead and are
+/// skipped here.
 pub fn apply_forces(
-    time: Res<Time>,
-    mut query: Query<(&Mass, &mut Velocity, &mut AppliedForce)>,
+    settings: Res<PhysicsSettings>,
+    mut query: Query<(&Mass, &mut Velocity, &mut AppliedForce), Without<PreviousAcceleration>>,
 ) {
-    let dt = time.delta_secs();
-    
+    let dt = settings.delta_time;
+
     for (mass, mut velocity, mut force) in query.iter_mut() {
         // Skip infinite mass objects and effectively massless objects
         if mass.is_infinite || mass.is_negligible() {
             continue;
         }
-        
+
         // Calculate acceleration using F = ma with safety against division by zero
-        let acceleration = force.force * mass.inverse();
-        
-        // Cap extremely high accelerations to prevent instability
-        let max_acceleration = 1000.0; // Arbitrary limit to prevent numerical issues
-        let acceleration = if acceleration.norm_squared() > max_acceleration * max_acceleration {
-            acceleration.normalize() * max_acceleration
-        } else {
-            acceleration
-        };
-        
+        let acceleration = capped_acceleration(force.force * mass.inverse());
+
         // Update velocity using acceleration
         velocity.linvel += acceleration * dt;
-        
+
         // Update force duration
         force.elapsed += dt;
     }
 }
 
-/// System to apply Verlet integration for position updates
+/// System that integrates position from the velocity [`apply_forces`] already updated
+/// this step, using the fixed [`PhysicsSettings::delta_time`] sub-step. Entities with
+/// [`PreviousAcceleration`] are integrated by [`integrate_positions_verlet`] instead.
 pub fn integrate_positions(
-    time: Res<Time>,
-    mut query: Query<(&Velocity, &mut Transform)>,
+    settings: Res<PhysicsSettings>,
+    mut query: Query<(&Velocity, &mut Transform), Without<PreviousAcceleration>>,
 ) {
-    let dt = time.delta_secs();
-    
+    let dt = settings.delta_time;
+
     for (velocity, mut transform) in query.iter_mut() {
         // Update position using velocity
         transform.translation += velocity.linvel * dt;
-        
+
         // Apply angular velocity
         if velocity.angvel.norm_squared() > 0.0 {
             transform.rotation *= Quat::from_scaled_axis(velocity.angvel * dt);
@@ -179,6 +194,60 @@ pub fn integrate_positions(
     }
 }
 
+/// Component storing the acceleration computed on an entity's previous
+/// [`integrate_positions_verlet`] step. Attaching this component to an entity opts it
+/// into velocity-Verlet integration instead of the symplectic Euler done by
+/// [`apply_forces`]/[`integrate_positions`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct PreviousAcceleration(pub Vec3);
+
+/// System that integrates position and velocity using velocity-Verlet, which is
+/// second-order accurate and nearly conserves total (kinetic plus potential) energy over
+/// long runs for oscillatory systems (springs, orbits, waves) where symplectic Euler
+/// slowly drifts.
+///
+/// Each step: advance position with the *previous* acceleration, recompute acceleration
+/// from the current [`AppliedForce`], then update velocity using the average of the
+/// previous and new acceleration.
+pub fn integrate_positions_verlet(
+    settings: Res<PhysicsSettings>,
+    mut query: Query<(
+        &Mass,
+        &mut Velocity,
+        &mut Transform,
+        &mut AppliedForce,
+        &mut PreviousAcceleration,
+    )>,
+) {
+    let dt = settings.delta_time;
+
+    for (mass, mut velocity, mut transform, mut force, mut previous_acceleration) in
+        query.iter_mut()
+    {
+        if mass.is_infinite || mass.is_negligible() {
+            continue;
+        }
+
+        let a_prev = previous_acceleration.0;
+        transform.translation += velocity.linvel * dt + 0.5 * a_prev * dt * dt;
+
+        // Angular velocity has no Verlet treatment here (no accumulated torque to
+        // average against), so it's integrated the same way integrate_positions does.
+        if velocity.angvel.norm_squared() > 0.0 {
+            transform.rotation *= Quat::from_scaled_axis(velocity.angvel * dt);
+        }
+
+        let a_new = capped_acceleration(force.force * mass.inverse());
+        velocity.linvel += 0.5 * (a_prev + a_new) * dt;
+
+        previous_acceleration.0 = a_new;
+
+        // Keep AppliedForce::elapsed advancing here too, matching apply_forces, so
+        // with_duration/is_expired still work for entities on the Verlet path.
+        force.elapsed += dt;
+    }
+}
+
 /// Component for velocity (both linear and angular)
 #[derive(Component, Debug, Clone, Copy)]
 pub struct Velocity {
@@ -197,6 +266,30 @@ impl Default for Velocity {
     }
 }
 
+impl Velocity {
+    /// Computes the linear and angular velocity needed to move a body from `start` to
+    /// `end` over `time`, for driving kinematic platforms and scripted motions from
+    /// target poses rather than per-frame deltas. Returns zero velocity for `time <= 0`
+    /// rather than dividing by it, since that would otherwise poison the result with
+    /// `inf`/`NaN`.
+    pub fn between_positions(start: &Transform, end: &Transform, time: f32) -> Self {
+        if time <= 0.0 {
+            return Self::default();
+        }
+
+        let linvel = (end.translation - start.translation) / time;
+
+        // Relative rotation from start to end, as a scaled-axis vector (axis * angle,
+        // shortest arc), gives the angular velocity needed to rotate over `time`.
+        let delta = end.rotation * start.rotation.inverse();
+        // `q` and `-q` are the same rotation; picking `w >= 0` keeps the angle <= pi.
+        let delta = if delta.w < 0.0 { -delta } else { delta };
+        let angvel = delta.to_scaled_axis() / time;
+
+        Self { linvel, angvel }
+    }
+}
+
 /// Calculate momentum of an object
 pub fn calculate_momentum(mass: &Mass, velocity: &Velocity) -> Vec3 {
     mass.value * velocity.linvel
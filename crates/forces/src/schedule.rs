@@ -0,0 +1,111 @@
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+
+use crate::drift::{remove_system_drift, ConserveMomentum};
+use crate::flocking::flock;
+use crate::mechanics::{apply_forces, integrate_positions, integrate_positions_verlet};
+
+/// Dedicated schedule for physics integration, stepped at a fixed `delta_time` rather
+/// than the variable frame time `Update` runs at. Keeping the step fixed is what makes
+/// the symplectic Euler integration in [`PhysicsSet::Main`] stable and frame-rate
+/// independent instead of slowly drifting in energy.
+#[derive(ScheduleLabel, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct PhysicsSchedule;
+
+/// Ordering of systems within [`PhysicsSchedule`].
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum PhysicsSet {
+    /// Runs before integration, e.g. force generators (flocking, springs).
+    First,
+    /// Force application and position integration, in that order.
+    Main,
+    /// Runs after integration, e.g. collision response, drift removal.
+    Last,
+}
+
+/// Configuration for the fixed-step physics loop.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct PhysicsSettings {
+    /// Fixed sub-step duration in seconds.
+    pub delta_time: f32,
+    /// Multiplier applied to accumulated real time, e.g. for slow motion or fast-forward.
+    pub time_scale: f32,
+}
+
+impl Default for PhysicsSettings {
+    fn default() -> Self {
+        Self {
+            delta_time: 1.0 / 60.0,
+            time_scale: 1.0,
+        }
+    }
+}
+
+/// Accumulates scaled real time so [`PhysicsSchedule`] can run zero or more fixed
+/// `delta_time` sub-steps per frame.
+#[derive(Resource, Debug, Default)]
+struct PhysicsTimeAccumulator(f32);
+
+/// Upper bound on physics sub-steps run in a single frame. Without this, a long frame
+/// (load hitch, breakpoint, backgrounded window) would queue up an unbounded number of
+/// catch-up steps and the next frame would take even longer to simulate them — the
+/// classic fixed-timestep "spiral of death."
+const MAX_STEPS_PER_FRAME: u32 = 8;
+
+/// Accumulates real elapsed time scaled by [`PhysicsSettings::time_scale`] and runs
+/// [`PhysicsSchedule`] once per fixed `delta_time` worth of accumulated time, up to
+/// [`MAX_STEPS_PER_FRAME`] times. Any time beyond that is dropped rather than queued up.
+pub fn run_physics_schedule(world: &mut World) {
+    let settings = *world.resource::<PhysicsSettings>();
+    let scaled_dt = world.resource::<Time>().delta_secs() * settings.time_scale;
+
+    world
+        .get_resource_or_insert_with(PhysicsTimeAccumulator::default)
+        .0 += scaled_dt;
+
+    let max_accumulated = settings.delta_time * MAX_STEPS_PER_FRAME as f32;
+    let mut accumulator = world.resource_mut::<PhysicsTimeAccumulator>();
+    if accumulator.0 > max_accumulated {
+        accumulator.0 = max_accumulated;
+    }
+    drop(accumulator);
+
+    let mut steps = 0;
+    while world.resource::<PhysicsTimeAccumulator>().0 >= settings.delta_time
+        && steps < MAX_STEPS_PER_FRAME
+    {
+        world.run_schedule(PhysicsSchedule);
+        world.resource_mut::<PhysicsTimeAccumulator>().0 -= settings.delta_time;
+        steps += 1;
+    }
+}
+
+/// Plugin wiring the fixed-step physics schedule into the app, driven from `Update`.
+pub struct PhysicsSchedulePlugin;
+
+impl Plugin for PhysicsSchedulePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsSettings>()
+            .init_resource::<PhysicsTimeAccumulator>()
+            .init_resource::<ConserveMomentum>()
+            .init_schedule(PhysicsSchedule)
+            .configure_sets(
+                PhysicsSchedule,
+                (PhysicsSet::First, PhysicsSet::Main, PhysicsSet::Last).chain(),
+            )
+            .add_systems(PhysicsSchedule, flock.in_set(PhysicsSet::First))
+            .add_systems(
+                PhysicsSchedule,
+                (
+                    (apply_forces, integrate_positions).chain(),
+                    integrate_positions_verlet,
+                )
+                    .in_set(PhysicsSet::Main),
+            )
+            .add_systems(
+                PhysicsSchedule,
+                remove_system_drift.in_set(PhysicsSet::Last),
+            )
+            .add_systems(Update, run_physics_schedule);
+    }
+}